@@ -22,21 +22,339 @@ use crate::config::Config;
 /// A single PlatformConfig represents a single platform. Each field represents a set of
 /// platform attributes which are true for this platform. A non-present attribute means
 /// "doesn't matter" or "all possible values".
-#[derive(Debug, Clone, Default, Deserialize)]
+/// Exceptions: a missing `target_feature` means no features are enabled, and
+/// [`PlatformPredicate::eval_strict`] treats any missing attribute as matching nothing.
+#[derive(Debug, Clone, Default)]
 pub struct PlatformConfig(HashMap<String, HashSet<String>>);
 
+/// Config representation of a `PlatformConfig`: either a rustc target triple
+/// shorthand (expanded via [`PlatformConfig::from_triple`]) or an explicit
+/// attribute map.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum PlatformConfigRepr {
+    Triple(String),
+    Attrs(HashMap<String, HashSet<String>>),
+}
+
+impl<'de> Deserialize<'de> for PlatformConfig {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        match PlatformConfigRepr::deserialize(deserializer)? {
+            PlatformConfigRepr::Triple(triple) => {
+                PlatformConfig::from_triple(&triple).ok_or_else(|| {
+                    <D::Error as serde::de::Error>::custom(format!(
+                        "unknown target triple `{}`",
+                        triple
+                    ))
+                })
+            }
+            PlatformConfigRepr::Attrs(map) => Ok(PlatformConfig(map)),
+        }
+    }
+}
+
+impl PlatformConfig {
+    /// The `target_feature`s configured for this platform; see
+    /// [`PlatformPredicate::eval`]'s dedicated `target_feature` branch.
+    fn target_features(&self) -> Option<&HashSet<String>> {
+        self.0.get("target_feature")
+    }
+
+    /// Synthesizes a `PlatformConfig` from a rustc target triple (e.g.
+    /// `x86_64-unknown-linux-gnu`), so users can name a platform by triple
+    /// instead of transcribing its `cfg` attributes by hand.
+    pub fn from_triple(triple: &str) -> Option<PlatformConfig> {
+        let attrs = builtin_target_triple(triple)?;
+        let mut map: HashMap<String, HashSet<String>> = HashMap::new();
+
+        let mut set = |key: &str, value: &str| {
+            if value.is_empty() {
+                return;
+            }
+            map.entry(key.to_string())
+                .or_default()
+                .insert(value.to_string());
+        };
+
+        set("target_arch", attrs.arch);
+        set("target_os", attrs.os);
+        set("target_env", attrs.env);
+        set("target_abi", attrs.abi);
+        set("target_vendor", attrs.vendor);
+        set("target_family", attrs.family);
+        set("target_endian", attrs.endian);
+        set("target_pointer_width", attrs.pointer_width);
+
+        if attrs.family == "unix" {
+            map.entry("unix".to_string()).or_default();
+        }
+        if attrs.family == "windows" {
+            map.entry("windows".to_string()).or_default();
+        }
+
+        Some(PlatformConfig(map))
+    }
+
+    /// Whether this platform's attributes are a superset of `triple_attrs`
+    /// (typically produced by [`Self::from_triple`]).
+    fn matches_synthesized(&self, triple_attrs: &PlatformConfig) -> bool {
+        triple_attrs.0.iter().all(|(key, values)| {
+            self.0
+                .get(key)
+                .is_some_and(|self_values| values.is_subset(self_values))
+        })
+    }
+}
+
+/// The canonical `cfg` attributes for a rustc target triple, as carried by crates
+/// like cfg-expr and target-spec. Empty string means the attribute is unset for
+/// this target (e.g. `target_env` on most non-Linux targets).
+struct TargetTripleAttrs {
+    arch: &'static str,
+    os: &'static str,
+    env: &'static str,
+    abi: &'static str,
+    vendor: &'static str,
+    family: &'static str,
+    endian: &'static str,
+    pointer_width: &'static str,
+}
+
+/// A small builtin database of well-known rustc target triples, sufficient to
+/// expand `PlatformConfig::from_triple` for the most commonly vendored targets.
+/// This is not the full list rustc ships; unrecognized triples fall through to
+/// `None` so they can still be configured by hand.
+const BUILTIN_TARGET_TRIPLES: &[(&str, TargetTripleAttrs)] = &[
+    (
+        "x86_64-unknown-linux-gnu",
+        TargetTripleAttrs {
+            arch: "x86_64",
+            os: "linux",
+            env: "gnu",
+            abi: "",
+            vendor: "unknown",
+            family: "unix",
+            endian: "little",
+            pointer_width: "64",
+        },
+    ),
+    (
+        "x86_64-unknown-linux-musl",
+        TargetTripleAttrs {
+            arch: "x86_64",
+            os: "linux",
+            env: "musl",
+            abi: "",
+            vendor: "unknown",
+            family: "unix",
+            endian: "little",
+            pointer_width: "64",
+        },
+    ),
+    (
+        "aarch64-unknown-linux-gnu",
+        TargetTripleAttrs {
+            arch: "aarch64",
+            os: "linux",
+            env: "gnu",
+            abi: "",
+            vendor: "unknown",
+            family: "unix",
+            endian: "little",
+            pointer_width: "64",
+        },
+    ),
+    (
+        "x86_64-apple-darwin",
+        TargetTripleAttrs {
+            arch: "x86_64",
+            os: "macos",
+            env: "",
+            abi: "",
+            vendor: "apple",
+            family: "unix",
+            endian: "little",
+            pointer_width: "64",
+        },
+    ),
+    (
+        "aarch64-apple-darwin",
+        TargetTripleAttrs {
+            arch: "aarch64",
+            os: "macos",
+            env: "",
+            abi: "",
+            vendor: "apple",
+            family: "unix",
+            endian: "little",
+            pointer_width: "64",
+        },
+    ),
+    (
+        "x86_64-pc-windows-msvc",
+        TargetTripleAttrs {
+            arch: "x86_64",
+            os: "windows",
+            env: "msvc",
+            abi: "",
+            vendor: "pc",
+            family: "windows",
+            endian: "little",
+            pointer_width: "64",
+        },
+    ),
+    (
+        "i686-pc-windows-msvc",
+        TargetTripleAttrs {
+            arch: "x86",
+            os: "windows",
+            env: "msvc",
+            abi: "",
+            vendor: "pc",
+            family: "windows",
+            endian: "little",
+            pointer_width: "32",
+        },
+    ),
+    (
+        "x86_64-pc-windows-gnu",
+        TargetTripleAttrs {
+            arch: "x86_64",
+            os: "windows",
+            env: "gnu",
+            abi: "",
+            vendor: "pc",
+            family: "windows",
+            endian: "little",
+            pointer_width: "64",
+        },
+    ),
+    (
+        "x86_64-unknown-freebsd",
+        TargetTripleAttrs {
+            arch: "x86_64",
+            os: "freebsd",
+            env: "",
+            abi: "",
+            vendor: "unknown",
+            family: "unix",
+            endian: "little",
+            pointer_width: "64",
+        },
+    ),
+    (
+        "aarch64-linux-android",
+        TargetTripleAttrs {
+            arch: "aarch64",
+            os: "android",
+            env: "",
+            abi: "",
+            vendor: "unknown",
+            family: "unix",
+            endian: "little",
+            pointer_width: "64",
+        },
+    ),
+    (
+        "armv7-linux-androideabi",
+        TargetTripleAttrs {
+            arch: "arm",
+            os: "android",
+            env: "",
+            abi: "eabi",
+            vendor: "unknown",
+            family: "unix",
+            endian: "little",
+            pointer_width: "32",
+        },
+    ),
+    (
+        "wasm32-unknown-unknown",
+        TargetTripleAttrs {
+            arch: "wasm32",
+            os: "",
+            env: "",
+            abi: "",
+            vendor: "unknown",
+            family: "",
+            endian: "little",
+            pointer_width: "32",
+        },
+    ),
+];
+
+fn builtin_target_triple(triple: &str) -> Option<&'static TargetTripleAttrs> {
+    BUILTIN_TARGET_TRIPLES
+        .iter()
+        .find(|(t, _)| *t == triple)
+        .map(|(_, attrs)| attrs)
+}
+
+/// Whether a `Value` predicate on a missing attribute key matches (`Open`) or
+/// not (`Closed`). `Bool` and `target_feature` predicates on a missing key never
+/// match in either mode. See [`PlatformPredicate::eval`] and [`PlatformPredicate::eval_strict`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Strictness {
+    Open,
+    Closed,
+}
+
+impl Strictness {
+    fn missing_key_default(self) -> bool {
+        match self {
+            Strictness::Open => true,
+            Strictness::Closed => false,
+        }
+    }
+}
+
 pub fn platform_names_for_expr<'config>(
     config: &'config Config,
     expr: &PlatformExpr,
 ) -> Result<Vec<&'config PlatformName>, PredicateParseError> {
-    let pred = PlatformPredicate::parse(expr)?;
+    platform_names_for_expr_mode(config, expr, Strictness::Open)
+}
 
-    let res = config
-        .platform
-        .iter()
-        .filter(|(_name, platconfig)| pred.eval(platconfig))
-        .map(|(name, _config)| name)
-        .collect();
+/// As [`platform_names_for_expr`], but resolves `expr` in closed-world mode
+/// (see [`PlatformPredicate::eval_strict`]) so platforms with partially
+/// specified attributes aren't over-selected.
+pub fn platform_names_for_expr_strict<'config>(
+    config: &'config Config,
+    expr: &PlatformExpr,
+) -> Result<Vec<&'config PlatformName>, PredicateParseError> {
+    platform_names_for_expr_mode(config, expr, Strictness::Closed)
+}
+
+fn platform_names_for_expr_mode<'config>(
+    config: &'config Config,
+    expr: &PlatformExpr,
+    strictness: Strictness,
+) -> Result<Vec<&'config PlatformName>, PredicateParseError> {
+    let res = match ParsedPlatformExpr::parse(expr)? {
+        ParsedPlatformExpr::Predicate(pred) => config
+            .platform
+            .iter()
+            .filter(|(_name, platconfig)| pred.eval_mode(platconfig, strictness))
+            .map(|(name, _config)| name)
+            .collect(),
+        ParsedPlatformExpr::NamedTriple(triple) => {
+            let synthesized = PlatformConfig::from_triple(triple);
+            config
+                .platform
+                .iter()
+                .filter(|(name, platconfig)| {
+                    name.as_str() == triple
+                        || synthesized
+                            .as_ref()
+                            .is_some_and(|synth| platconfig.matches_synthesized(synth))
+                })
+                .map(|(name, _config)| name)
+                .collect()
+        }
+    };
     Ok(res)
 }
 
@@ -44,8 +362,7 @@ pub fn platform_names_for_expr<'config>(
 const DEFAULT_PLATFORM: &str = "DEFAULT";
 
 /// A name of a platform, as used in Config.
-#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
-#[derive(Serialize, Deserialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct PlatformName(String);
 
@@ -53,6 +370,10 @@ impl PlatformName {
     pub fn is_default(&self) -> bool {
         self.0 == DEFAULT_PLATFORM
     }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
 }
 
 impl Display for PlatformName {
@@ -63,8 +384,7 @@ impl Display for PlatformName {
 
 /// A Cargo-style platform predicate expression
 /// such as `cfg(target_arch = "z80")`.
-#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
-#[derive(Serialize, Deserialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct PlatformExpr(String);
 
@@ -80,6 +400,30 @@ impl Display for PlatformExpr {
     }
 }
 
+/// A `PlatformExpr`, parsed into the two forms cargo-platform distinguishes: a
+/// `cfg(...)` predicate, or a bare target triple like `x86_64-apple-darwin`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ParsedPlatformExpr<'a> {
+    Predicate(PlatformPredicate<'a>),
+    NamedTriple(&'a str),
+}
+
+impl<'a> ParsedPlatformExpr<'a> {
+    pub fn parse(input: &'a PlatformExpr) -> Result<Self, PredicateParseError> {
+        if input.0.starts_with("cfg(") {
+            return Ok(ParsedPlatformExpr::Predicate(PlatformPredicate::parse(
+                input,
+            )?));
+        }
+        if input.0.contains('(') {
+            return Err(PredicateParseError::ParseError(
+                "cfg expressions must start with `cfg(`".to_string(),
+            ));
+        }
+        Ok(ParsedPlatformExpr::NamedTriple(&input.0))
+    }
+}
+
 /// Platform predicate which can be matched against a PlatformConfig
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum PlatformPredicate<'a> {
@@ -128,28 +472,260 @@ impl<'a> PlatformPredicate<'a> {
         }
     }
 
+    /// Open-world evaluation: a `Value` predicate for a key absent from `config`
+    /// matches, on the assumption the platform just wasn't fully specified.
+    /// `target_feature` is the exception: with no features listed, it never matches.
     pub fn eval(&self, config: &PlatformConfig) -> bool {
+        self.eval_mode(config, Strictness::Open)
+    }
+
+    /// Closed-world evaluation: a `Value` predicate for a key absent from
+    /// `config` does *not* match, the way Cargo resolves `cfg` against a
+    /// concrete `rustc --print cfg` output.
+    pub fn eval_strict(&self, config: &PlatformConfig) -> bool {
+        self.eval_mode(config, Strictness::Closed)
+    }
+
+    fn eval_mode(&self, config: &PlatformConfig, strictness: Strictness) -> bool {
         use PlatformPredicate::*;
 
         match self {
             Bool { key } => config.0.contains_key(*key),
-            Value { key, value } => config.0.get(*key).map_or(true, |set| set.contains(*value)),
-            Not(pred) => !pred.eval(config),
-            Any(preds) => preds.iter().any(|pred| pred.eval(config)),
-            All(preds) => preds.iter().all(|pred| pred.eval(config)),
-            Unix => self.target_family_bool("unix", config),
-            Windows => self.target_family_bool("windows", config),
+            // A missing `target_feature` key means none are enabled, not "matches
+            // anything", regardless of the open/closed-world mode in effect.
+            Value {
+                key: "target_feature",
+                value,
+            } => config
+                .target_features()
+                .is_some_and(|set| set.contains(*value)),
+            Value { key, value } => config
+                .0
+                .get(*key)
+                .map_or(strictness.missing_key_default(), |set| set.contains(*value)),
+            Not(pred) => !pred.eval_mode(config, strictness),
+            Any(preds) => preds.iter().any(|pred| pred.eval_mode(config, strictness)),
+            All(preds) => preds.iter().all(|pred| pred.eval_mode(config, strictness)),
+            Unix => self.target_family_bool("unix", config, strictness),
+            Windows => self.target_family_bool("windows", config, strictness),
         }
     }
 
-    fn target_family_bool(&self, family: &str, config: &PlatformConfig) -> bool {
-        PlatformPredicate::Bool { key: family }.eval(config)
+    fn target_family_bool(
+        &self,
+        family: &str,
+        config: &PlatformConfig,
+        strictness: Strictness,
+    ) -> bool {
+        PlatformPredicate::Bool { key: family }.eval_mode(config, strictness)
             || PlatformPredicate::Value {
                 key: "target_family",
                 value: family,
             }
-            .eval(config)
+            .eval_mode(config, strictness)
+    }
+
+    /// Returns false if this predicate is structurally impossible to satisfy,
+    /// e.g. `all(target_os = "linux", target_os = "macos")`. Normalizes to
+    /// disjunctive normal form and checks each conjunction for contradictions.
+    ///
+    /// Gives up and returns `true` (skipping the warning rather than risking
+    /// unbounded work) if the expression is branchy enough to blow past
+    /// [`MAX_DNF_TERMS`] while normalizing -- `platform_deps` expressions come
+    /// from arbitrary vendored `Cargo.toml` files, so this needs a hard cap.
+    pub fn is_satisfiable(&self) -> bool {
+        match self.to_dnf(false) {
+            Some(dnf) => dnf.iter().any(|term| term_is_satisfiable(term)),
+            None => true,
+        }
+    }
+
+    /// Converts this predicate to disjunctive normal form: a list of conjunctions
+    /// (the outer list is implicitly `Any`-ed together) of `Literal`s, or `None`
+    /// if it grows past [`MAX_DNF_TERMS`] terms. `negated` tracks whether we're
+    /// currently underneath a `Not`, so De Morgan's laws can be applied on the
+    /// way down rather than building a `Not`-laden tree first.
+    fn to_dnf(&self, negated: bool) -> Option<Vec<Vec<Literal<'a>>>> {
+        use PlatformPredicate::*;
+
+        let dnf = match self {
+            Value { key, value } => vec![vec![Literal::Value {
+                key,
+                value,
+                negated,
+            }]],
+            Bool { key } => vec![vec![Literal::Bool { key, negated }]],
+            // Mirrors `target_family_bool`: `unix`/`windows` is true either via the
+            // bare bool key or via `target_family`, so both disjuncts must appear
+            // here too, or `is_satisfiable` under-approximates what can match.
+            Unix => {
+                return Any(vec![
+                    Bool { key: "unix" },
+                    Value {
+                        key: "target_family",
+                        value: "unix",
+                    },
+                ])
+                .to_dnf(negated);
+            }
+            Windows => {
+                return Any(vec![
+                    Bool { key: "windows" },
+                    Value {
+                        key: "target_family",
+                        value: "windows",
+                    },
+                ])
+                .to_dnf(negated);
+            }
+            Not(pred) => return pred.to_dnf(!negated),
+            // not(all(a, b)) == any(not a, not b): either way, union the terms.
+            All(preds) if negated => union_capped(preds.iter().map(|p| p.to_dnf(true)))?,
+            // all(a, b): every combination of a term from a with a term from b.
+            All(preds) => preds.iter().try_fold(vec![vec![]], |acc, p| {
+                checked_cross_product(acc, p.to_dnf(false)?)
+            })?,
+            // not(any(a, b)) == all(not a, not b): cross product of the negated terms.
+            Any(preds) if negated => preds.iter().try_fold(vec![vec![]], |acc, p| {
+                checked_cross_product(acc, p.to_dnf(true)?)
+            })?,
+            // any(a, b): union the terms.
+            Any(preds) => union_capped(preds.iter().map(|p| p.to_dnf(false)))?,
+        };
+        capped(dnf)
+    }
+}
+
+/// Upper bound on the number of DNF terms `to_dnf` will expand to. Nested
+/// `all(any(..), any(..), ...)` grows the term count exponentially; past this
+/// threshold `to_dnf` gives up rather than risk unbounded allocation.
+const MAX_DNF_TERMS: usize = 4096;
+
+fn capped<'a>(dnf: Vec<Vec<Literal<'a>>>) -> Option<Vec<Vec<Literal<'a>>>> {
+    (dnf.len() <= MAX_DNF_TERMS).then_some(dnf)
+}
+
+fn union_capped<'a>(
+    terms: impl Iterator<Item = Option<Vec<Vec<Literal<'a>>>>>,
+) -> Option<Vec<Vec<Literal<'a>>>> {
+    let mut result = Vec::new();
+    for term in terms {
+        result.extend(term?);
+        if result.len() > MAX_DNF_TERMS {
+            return None;
+        }
+    }
+    Some(result)
+}
+
+/// One literal (possibly negated) in a DNF conjunction.
+#[derive(Debug, Clone, Eq, PartialEq)]
+enum Literal<'a> {
+    Bool {
+        key: &'a str,
+        negated: bool,
+    },
+    Value {
+        key: &'a str,
+        value: &'a str,
+        negated: bool,
+    },
+}
+
+/// Keys for which a `PlatformConfig` holds at most one value, so two different
+/// required values for the same key in a conjunction can never both hold.
+/// `target_family` and `target_feature` are deliberately excluded: a platform can
+/// legitimately have several families or features at once.
+const SINGLE_VALUED_KEYS: &[&str] = &[
+    "target_arch",
+    "target_os",
+    "target_env",
+    "target_abi",
+    "target_endian",
+    "target_pointer_width",
+    "target_vendor",
+];
+
+/// Like [`cross_product`], but checks the cap *before* multiplying out, so a
+/// pair of large terms doesn't get fully cross-multiplied just to be thrown
+/// away by [`capped`] afterwards.
+fn checked_cross_product<'a>(
+    acc: Vec<Vec<Literal<'a>>>,
+    sub: Vec<Vec<Literal<'a>>>,
+) -> Option<Vec<Vec<Literal<'a>>>> {
+    if acc.len().saturating_mul(sub.len()) > MAX_DNF_TERMS {
+        return None;
+    }
+    Some(cross_product(acc, sub))
+}
+
+fn cross_product<'a>(
+    acc: Vec<Vec<Literal<'a>>>,
+    sub: Vec<Vec<Literal<'a>>>,
+) -> Vec<Vec<Literal<'a>>> {
+    let mut result = Vec::with_capacity(acc.len() * sub.len());
+    for a in &acc {
+        for s in &sub {
+            let mut term = a.clone();
+            term.extend(s.iter().cloned());
+            result.push(term);
+        }
+    }
+    result
+}
+
+/// Checks a single DNF conjunction for internal contradictions.
+fn term_is_satisfiable(term: &[Literal]) -> bool {
+    let mut required_bools = HashSet::new();
+    let mut negated_bools = HashSet::new();
+    let mut required_values: HashMap<&str, HashSet<&str>> = HashMap::new();
+    let mut negated_values = HashSet::new();
+
+    for lit in term {
+        match *lit {
+            Literal::Bool { key, negated: true } => {
+                negated_bools.insert(key);
+            }
+            Literal::Bool {
+                key,
+                negated: false,
+            } => {
+                required_bools.insert(key);
+            }
+            Literal::Value {
+                key,
+                value,
+                negated: true,
+            } => {
+                negated_values.insert((key, value));
+            }
+            Literal::Value {
+                key,
+                value,
+                negated: false,
+            } => {
+                required_values.entry(key).or_default().insert(value);
+            }
+        }
+    }
+
+    if required_bools.intersection(&negated_bools).next().is_some() {
+        return false;
+    }
+
+    for (key, values) in &required_values {
+        if SINGLE_VALUED_KEYS.contains(key) && values.len() > 1 {
+            return false;
+        }
+        if values
+            .iter()
+            .any(|value| negated_values.contains(&(*key, *value)))
+        {
+            return false;
+        }
     }
+
+    true
 }
 
 impl<'a> Display for PlatformPredicate<'a> {
@@ -167,3 +743,165 @@ impl<'a> Display for PlatformPredicate<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_triple_expands_known_attrs() {
+        let config = PlatformConfig::from_triple("x86_64-unknown-linux-gnu").unwrap();
+        assert!(PlatformPredicate::Value {
+            key: "target_os",
+            value: "linux"
+        }
+        .eval(&config));
+        assert!(PlatformPredicate::Unix.eval(&config));
+        assert!(!PlatformPredicate::Windows.eval(&config));
+    }
+
+    #[test]
+    fn from_triple_rejects_unknown_triple() {
+        assert!(PlatformConfig::from_triple("bogus-triple").is_none());
+    }
+
+    #[test]
+    fn platform_config_deserializes_triple_shorthand() {
+        let config: PlatformConfig = serde_json::from_str("\"x86_64-apple-darwin\"").unwrap();
+        assert!(PlatformPredicate::Value {
+            key: "target_vendor",
+            value: "apple"
+        }
+        .eval(&config));
+    }
+
+    #[test]
+    fn platform_config_deserializes_explicit_attrs() {
+        let config: PlatformConfig = serde_json::from_str(r#"{"target_os": ["redox"]}"#).unwrap();
+        assert!(PlatformPredicate::Value {
+            key: "target_os",
+            value: "redox"
+        }
+        .eval(&config));
+    }
+
+    #[test]
+    fn platform_config_rejects_unknown_triple_shorthand() {
+        let err = serde_json::from_str::<PlatformConfig>("\"not-a-real-triple\"").unwrap_err();
+        assert!(err.to_string().contains("unknown target triple"));
+    }
+
+    #[test]
+    fn conflicting_target_os_is_unsatisfiable() {
+        let expr: PlatformExpr = r#"cfg(all(target_os = "linux", target_os = "macos"))"#
+            .to_string()
+            .into();
+        assert!(!PlatformPredicate::parse(&expr).unwrap().is_satisfiable());
+    }
+
+    #[test]
+    fn conflicting_target_abi_is_unsatisfiable() {
+        let expr: PlatformExpr = r#"cfg(all(target_abi = "eabi", target_abi = "eabihf"))"#
+            .to_string()
+            .into();
+        assert!(!PlatformPredicate::parse(&expr).unwrap().is_satisfiable());
+    }
+
+    #[test]
+    fn unix_via_bool_key_is_satisfiable_without_target_family() {
+        // Under `eval_strict`, a platform like `{"unix": []}` with no
+        // `target_family` key makes this true: `unix` holds via the bare bool
+        // key and the absent `target_family` doesn't match "unix".
+        // `is_satisfiable` must not drop that disjunct.
+        let expr: PlatformExpr =
+            r#"cfg(all(unix, not(target_family = "unix")))"#.to_string().into();
+        assert!(PlatformPredicate::parse(&expr).unwrap().is_satisfiable());
+    }
+
+    #[test]
+    fn multiple_target_features_are_satisfiable() {
+        let expr: PlatformExpr = r#"cfg(all(target_feature = "sse2", target_feature = "avx2"))"#
+            .to_string()
+            .into();
+        assert!(PlatformPredicate::parse(&expr).unwrap().is_satisfiable());
+    }
+
+    #[test]
+    fn target_feature_requires_all_listed_features() {
+        let config: PlatformConfig =
+            serde_json::from_str(r#"{"target_feature": ["sse2"]}"#).unwrap();
+        let expr: PlatformExpr = r#"cfg(all(target_feature = "sse2", target_feature = "avx2"))"#
+            .to_string()
+            .into();
+        assert!(!PlatformPredicate::parse(&expr).unwrap().eval(&config));
+    }
+
+    #[test]
+    fn not_target_feature_is_true_when_unset() {
+        let config = PlatformConfig::default();
+        let expr: PlatformExpr = r#"cfg(not(target_feature = "crt-static"))"#.to_string().into();
+        assert!(PlatformPredicate::parse(&expr).unwrap().eval(&config));
+    }
+
+    #[test]
+    fn missing_key_matches_anything_in_open_mode_only() {
+        let config: PlatformConfig =
+            serde_json::from_str(r#"{"target_arch": ["x86_64"]}"#).unwrap();
+        let expr: PlatformExpr = r#"cfg(target_os = "redox")"#.to_string().into();
+        let pred = PlatformPredicate::parse(&expr).unwrap();
+        assert!(pred.eval(&config));
+        assert!(!pred.eval_strict(&config));
+    }
+
+    #[test]
+    fn bare_triple_parses_as_named_triple() {
+        let expr: PlatformExpr = "x86_64-apple-darwin".to_string().into();
+        assert_eq!(
+            ParsedPlatformExpr::parse(&expr).unwrap(),
+            ParsedPlatformExpr::NamedTriple("x86_64-apple-darwin")
+        );
+    }
+
+    #[test]
+    fn cfg_expr_parses_as_predicate() {
+        let expr: PlatformExpr = r#"cfg(unix)"#.to_string().into();
+        assert!(matches!(
+            ParsedPlatformExpr::parse(&expr).unwrap(),
+            ParsedPlatformExpr::Predicate(PlatformPredicate::Unix)
+        ));
+    }
+
+    #[test]
+    fn malformed_name_is_rejected() {
+        let expr: PlatformExpr = "not_cfg(foo)".to_string().into();
+        let err = ParsedPlatformExpr::parse(&expr).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("cfg expressions must start with `cfg(`"));
+    }
+
+    #[test]
+    fn platform_matches_synthesized_triple_attrs() {
+        let triple_attrs = PlatformConfig::from_triple("x86_64-apple-darwin").unwrap();
+        let config: PlatformConfig = serde_json::from_str(
+            r#"{"target_arch": ["x86_64"], "target_os": ["macos"], "target_vendor": ["apple"],
+                "target_family": ["unix"], "target_endian": ["little"],
+                "target_pointer_width": ["64"], "unix": []}"#,
+        )
+        .unwrap();
+        assert!(config.matches_synthesized(&triple_attrs));
+    }
+
+    #[test]
+    fn dnf_term_cap_falls_back_to_satisfiable() {
+        let mut pred = PlatformPredicate::Bool { key: "k" };
+        for _ in 0..14 {
+            let branch = PlatformPredicate::Any(vec![
+                PlatformPredicate::Bool { key: "a" },
+                PlatformPredicate::Bool { key: "b" },
+            ]);
+            pred = PlatformPredicate::All(vec![pred, branch]);
+        }
+        assert!(pred.is_satisfiable());
+    }
+}